@@ -1,7 +1,7 @@
+use crate::material::StrengthFactor;
 use crate::texture;
-use crate::validation::Checked;
-#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
-use crate::{material::StrengthFactor, validation::Validate, Extras};
+use crate::validation::{Checked, Error, Validate};
+use crate::{Extras, Path, Root};
 use gltf_derive::Validate;
 use serde::de;
 use serde_derive::{Deserialize, Serialize};
@@ -32,12 +32,308 @@ pub struct Material {
     pub ext_pbr_attributes: Option<PbrAttributes>,
     #[serde(default, rename = "AA_shadow", skip_serializing_if = "Option::is_none")]
     pub aa_shadow: Option<AAShadow>,
+    #[cfg(feature = "KHR_materials_ior")]
+    #[serde(
+        default,
+        rename = "KHR_materials_ior",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ior: Option<Ior>,
+    #[cfg(feature = "KHR_materials_specular")]
+    #[serde(
+        default,
+        rename = "KHR_materials_specular",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub specular: Option<Specular>,
+
+    /// A set of parameter values that are used to define the metallic-roughness
+    /// material model from Physically-Based Rendering (PBR) methodology.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pbr_metallic_roughness: Option<PbrMetallicRoughness>,
+
+    /// The emissive color of the material.
+    #[serde(default, rename = "emissiveFactor")]
+    pub emissive_factor: EmissiveFactor,
+
+    /// The alpha rendering mode of the material.
+    #[serde(default, rename = "alphaMode")]
+    pub alpha_mode: Checked<AlphaMode>,
+
+    /// The alpha cutoff value of the material.
+    #[serde(rename = "alphaCutoff", skip_serializing_if = "Option::is_none")]
+    pub alpha_cutoff: Option<AlphaCutoff>,
+
+    /// The emissive texture.
+    ///
+    /// This texture contains RGB components of the emissive color of the
+    /// material in sRGB color space.
+    #[serde(default, rename = "emissiveTexture", skip_serializing_if = "Option::is_none")]
+    pub emissive_texture: Option<texture::Info>,
+
+    /// The tangent-space normal texture.
+    #[serde(default, rename = "normalTexture", skip_serializing_if = "Option::is_none")]
+    pub normal_texture: Option<NormalTexture>,
+
+    /// The occlusion texture.
+    #[serde(
+        default,
+        rename = "occlusionTexture",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub occlusion_texture: Option<OcclusionTexture>,
+
+    /// Specifies whether the material is double-sided.
+    ///
+    /// When this value is false, back-face culling is enabled. When this value
+    /// is true, back-face culling is disabled and double-sided lighting is
+    /// enabled.
+    #[serde(default, rename = "doubleSided")]
+    pub double_sided: bool,
+}
+
+impl Material {
+    /// Returns the highest `TEXCOORD` set index referenced by any texture
+    /// bound to this material, or `0` if the material has no textures.
+    ///
+    /// Loaders can use this to determine how many UV attribute sets a mesh
+    /// primitive using this material needs to provide.
+    pub fn max_tex_coord(&self) -> u32 {
+        let mut max = 0;
+        if let Some(pbr) = self.pbr_metallic_roughness.as_ref() {
+            max = max.max(pbr.max_tex_coord());
+        }
+        if let Some(info) = self.emissive_texture.as_ref() {
+            max = max.max(info.tex_coord);
+        }
+        if let Some(normal) = self.normal_texture.as_ref() {
+            max = max.max(normal.tex_coord);
+        }
+        if let Some(occlusion) = self.occlusion_texture.as_ref() {
+            max = max.max(occlusion.tex_coord);
+        }
+        if let Some(info) = self
+            .aa_shadow
+            .as_ref()
+            .and_then(|shadow| shadow.shadow_texture.as_ref())
+        {
+            max = max.max(info.tex_coord);
+        }
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        if let Some(pbr) = self.pbr_specular_glossiness.as_ref() {
+            max = max.max(pbr.max_tex_coord());
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        if let Some(specular) = self.specular.as_ref() {
+            max = max.max(specular.max_tex_coord());
+        }
+        max
+    }
 }
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct PbrMetallicRoughness {}
+#[serde(default, rename_all = "camelCase")]
+pub struct PbrMetallicRoughness {
+    /// The material's base color factor.
+    ///
+    /// The RGBA components of the base color of the material. The fourth
+    /// component (A) is the alpha coverage of the material. The `alphaMode`
+    /// property specifies how alpha is interpreted. These values are linear.
+    pub base_color_factor: PbrBaseColorFactor,
+
+    /// The base color texture.
+    ///
+    /// This texture contains RGB(A) components of the base color of the
+    /// material in sRGB color space. If the fourth component (A) is present,
+    /// it represents the alpha coverage of the material. Otherwise, an alpha
+    /// of 1.0 is assumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_color_texture: Option<texture::Info>,
+
+    /// The metalness of the material.
+    ///
+    /// A value of 1.0 means the material is a metal. A value of 0.0 means the
+    /// material is a dielectric. Values in between are for blending between
+    /// metals and dielectrics such as dirty metallic surfaces. This value is
+    /// linear.
+    pub metallic_factor: StrengthFactor,
+
+    /// The roughness of the material.
+    ///
+    /// A value of 1.0 means the material is completely rough. A value of 0.0
+    /// means the material is completely smooth. This value is linear.
+    pub roughness_factor: StrengthFactor,
+
+    /// The metallic-roughness texture.
+    ///
+    /// This texture has two components: roughness is sampled from the G
+    /// channel, metalness is sampled from the B channel. These values are
+    /// linear. If other channels are present (R or A), they are ignored for
+    /// metallic-roughness calculations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metallic_roughness_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+impl PbrMetallicRoughness {
+    /// Returns the highest `TEXCOORD` set index referenced by this
+    /// material model's textures, or `0` if it has none.
+    pub fn max_tex_coord(&self) -> u32 {
+        self.base_color_texture
+            .as_ref()
+            .map_or(0, |info| info.tex_coord)
+            .max(
+                self.metallic_roughness_texture
+                    .as_ref()
+                    .map_or(0, |info| info.tex_coord),
+            )
+    }
+}
+
+/// The base color factor of a material.
+///
+/// Each component must lie in `[0.0, 1.0]`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct PbrBaseColorFactor(pub [f32; 4]);
+
+impl Default for PbrBaseColorFactor {
+    fn default() -> Self {
+        PbrBaseColorFactor([1.0, 1.0, 1.0, 1.0])
+    }
+}
+
+impl Validate for PbrBaseColorFactor {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        if self.0.iter().any(|x| !(0.0..=1.0).contains(x)) {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The emissive color of a material.
+///
+/// Each component must lie in `[0.0, 1.0]`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct EmissiveFactor(pub [f32; 3]);
+
+impl Default for EmissiveFactor {
+    fn default() -> Self {
+        EmissiveFactor([0.0, 0.0, 0.0])
+    }
+}
+
+impl Validate for EmissiveFactor {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        if self.0.iter().any(|x| !(0.0..=1.0).contains(x)) {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The alpha cutoff value of a material.
+///
+/// Must be finite and non-negative.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct AlphaCutoff(pub f32);
+
+impl Default for AlphaCutoff {
+    fn default() -> Self {
+        AlphaCutoff(0.5)
+    }
+}
+
+impl Validate for AlphaCutoff {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        if !self.0.is_finite() || self.0 < 0.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The alpha rendering mode of a material.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AlphaMode {
+    /// The alpha value is ignored and the rendered output is fully opaque.
+    Opaque,
+
+    /// The rendered output is either fully opaque or fully transparent
+    /// depending on the alpha value and the specified alpha cutoff value.
+    Mask,
+
+    /// The alpha value is used to composite the source and destination
+    /// areas. The rendered output is combined with the background using
+    /// the normal painting operation.
+    Blend,
+}
+
+pub const VALID_ALPHA_MODES: &'static [&'static str] = &["OPAQUE", "MASK", "BLEND"];
+
+impl serde::Serialize for AlphaMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            AlphaMode::Opaque => serializer.serialize_str("OPAQUE"),
+            AlphaMode::Mask => serializer.serialize_str("MASK"),
+            AlphaMode::Blend => serializer.serialize_str("BLEND"),
+        }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Checked<AlphaMode> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Checked<AlphaMode>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "any of: {:?}", VALID_ALPHA_MODES)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                use self::AlphaMode::*;
+                use crate::validation::Checked::*;
+                Ok(match value {
+                    "OPAQUE" => Valid(Opaque),
+                    "MASK" => Valid(Mask),
+                    "BLEND" => Valid(Blend),
+                    _ => Invalid,
+                })
+            }
+        }
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl Default for AlphaMode {
+    fn default() -> Self {
+        AlphaMode::Opaque
+    }
+}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ColorSpace {
@@ -170,13 +466,304 @@ pub struct PbrSpecularGlossiness {
     pub extras: Extras,
 }
 
-/// Defines the normal texture of a material.
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+impl PbrSpecularGlossiness {
+    /// Converts this specular-glossiness material to an equivalent
+    /// metallic-roughness material, following the conversion recommended by
+    /// the `KHR_materials_pbrSpecularGlossiness` specification.
+    ///
+    /// Only the factor values are converted. Any `diffuse_texture` or
+    /// `specular_glossiness_texture` is dropped; callers that need to
+    /// preserve texture data should pass the source textures through
+    /// unchanged alongside the converted factors.
+    pub fn to_metallic_roughness(&self) -> PbrMetallicRoughness {
+        const DIELECTRIC_SPECULAR: f32 = 0.04;
+        const EPSILON: f32 = 1e-6;
+
+        fn luminance(rgb: [f32; 3]) -> f32 {
+            (0.2126 * rgb[0] * rgb[0] + 0.7152 * rgb[1] * rgb[1] + 0.0722 * rgb[2] * rgb[2]).sqrt()
+        }
+
+        let diffuse = self.diffuse_factor.0;
+        let specular = self.specular_factor.0;
+
+        let perceived_diffuse = luminance([diffuse[0], diffuse[1], diffuse[2]]);
+        let perceived_specular = luminance(specular);
+        let one_minus_specular_strength =
+            1.0 - specular[0].max(specular[1]).max(specular[2]);
+
+        let metallic = if perceived_specular < DIELECTRIC_SPECULAR {
+            0.0
+        } else {
+            let a = DIELECTRIC_SPECULAR;
+            let b = perceived_diffuse * one_minus_specular_strength / (1.0 - DIELECTRIC_SPECULAR)
+                + perceived_specular
+                - 2.0 * DIELECTRIC_SPECULAR;
+            let c = DIELECTRIC_SPECULAR - perceived_specular;
+            let d = (b * b - 4.0 * a * c).max(0.0);
+            ((-b + d.sqrt()) / (2.0 * a)).clamp(0.0, 1.0)
+        };
+
+        let base_color_from_diffuse = [
+            diffuse[0] * one_minus_specular_strength / (1.0 - DIELECTRIC_SPECULAR) / (1.0 - metallic).max(EPSILON),
+            diffuse[1] * one_minus_specular_strength / (1.0 - DIELECTRIC_SPECULAR) / (1.0 - metallic).max(EPSILON),
+            diffuse[2] * one_minus_specular_strength / (1.0 - DIELECTRIC_SPECULAR) / (1.0 - metallic).max(EPSILON),
+        ];
+        let base_color_from_specular = [
+            (specular[0] - DIELECTRIC_SPECULAR * (1.0 - metallic)) / metallic.max(EPSILON),
+            (specular[1] - DIELECTRIC_SPECULAR * (1.0 - metallic)) / metallic.max(EPSILON),
+            (specular[2] - DIELECTRIC_SPECULAR * (1.0 - metallic)) / metallic.max(EPSILON),
+        ];
+
+        // Weighted by `metallic * metallic` (not `metallic`) to match the
+        // reference conversion used by the Khronos sample viewer and
+        // three.js's `GLTFExporter`.
+        let metallic_weight = metallic * metallic;
+        let mut base_color_factor = [0.0; 4];
+        for (dst, (from_diffuse, from_specular)) in base_color_factor
+            .iter_mut()
+            .zip(base_color_from_diffuse.iter().zip(base_color_from_specular.iter()))
+        {
+            *dst = (from_diffuse * (1.0 - metallic_weight) + from_specular * metallic_weight)
+                .clamp(0.0, 1.0);
+        }
+        base_color_factor[3] = diffuse[3];
+
+        PbrMetallicRoughness {
+            base_color_factor: PbrBaseColorFactor(base_color_factor),
+            base_color_texture: None,
+            metallic_factor: StrengthFactor(metallic),
+            roughness_factor: StrengthFactor(1.0 - self.glossiness_factor.0),
+            metallic_roughness_texture: None,
+            extras: Default::default(),
+        }
+    }
+
+    /// Returns the highest `TEXCOORD` set index referenced by this
+    /// material model's textures, or `0` if it has none.
+    pub fn max_tex_coord(&self) -> u32 {
+        self.diffuse_texture
+            .as_ref()
+            .map_or(0, |info| info.tex_coord)
+            .max(
+                self.specular_glossiness_texture
+                    .as_ref()
+                    .map_or(0, |info| info.tex_coord),
+            )
+    }
+}
+
+/// Specifies the dielectric index of refraction, overriding the fixed
+/// `0.04` dielectric reflectance used by the metallic-roughness model.
+#[cfg(feature = "KHR_materials_ior")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Ior {
+    /// The index of refraction.
+    pub ior: IndexOfRefraction,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_ior")]
+impl Default for Ior {
+    fn default() -> Self {
+        Ior {
+            ior: IndexOfRefraction::default(),
+            extras: Default::default(),
+        }
+    }
+}
+
+/// The index of refraction of a material.
+///
+/// Must be greater than or equal to `1.0`.
+#[cfg(feature = "KHR_materials_ior")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct IndexOfRefraction(pub f32);
+
+#[cfg(feature = "KHR_materials_ior")]
+impl Default for IndexOfRefraction {
+    fn default() -> Self {
+        IndexOfRefraction(1.5)
+    }
+}
+
+#[cfg(feature = "KHR_materials_ior")]
+impl Validate for IndexOfRefraction {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        if !self.0.is_finite() || self.0 < 1.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// Reshapes the dielectric highlights of a material by scaling and
+/// tinting its specular reflection, independently of the base color.
+#[cfg(feature = "KHR_materials_specular")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct NormalTexture {}
+#[serde(default, rename_all = "camelCase")]
+pub struct Specular {
+    /// The strength of the dielectric specular reflection.
+    pub specular_factor: StrengthFactor,
+
+    /// A texture that defines the strength of the dielectric specular
+    /// reflection, stored in the texture's alpha (A) channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specular_texture: Option<texture::Info>,
+
+    /// The F0 color of the dielectric specular reflection.
+    pub specular_color_factor: SpecularColorFactor,
+
+    /// A texture that defines the F0 color of the dielectric specular
+    /// reflection, stored in the texture's RGB channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specular_color_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Specular {
+    /// Returns the highest `TEXCOORD` set index referenced by this
+    /// extension's textures, or `0` if it has none.
+    pub fn max_tex_coord(&self) -> u32 {
+        self.specular_texture
+            .as_ref()
+            .map_or(0, |info| info.tex_coord)
+            .max(
+                self.specular_color_texture
+                    .as_ref()
+                    .map_or(0, |info| info.tex_coord),
+            )
+    }
+}
+
+/// The F0 color of a material's dielectric specular reflection.
+///
+/// Each component must lie in `[0.0, 1.0]`.
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct SpecularColorFactor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Default for SpecularColorFactor {
+    fn default() -> Self {
+        SpecularColorFactor([1.0, 1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Validate for SpecularColorFactor {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        if self.0.iter().any(|x| !(0.0..=1.0).contains(x)) {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// Defines the normal texture of a material.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NormalTexture {
+    /// The index of the texture.
+    pub index: texture::Index<texture::Texture>,
+
+    /// The set index of the texture's `TEXCOORD` attribute.
+    #[serde(rename = "texCoord")]
+    pub tex_coord: u32,
+
+    /// The scalar parameter applied to each normal vector of the texture.
+    ///
+    /// This value scales the X and Y components of the sampled normal
+    /// vector using the formula:
+    /// `scaledNormal = normalize((<sampled normal texture value> * 2.0 - 1.0) * vec3(scale, scale, 1.0))`.
+    pub scale: NormalTextureScale,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+impl Default for NormalTexture {
+    fn default() -> Self {
+        NormalTexture {
+            index: Default::default(),
+            tex_coord: 0,
+            scale: NormalTextureScale::default(),
+            extras: Default::default(),
+        }
+    }
+}
+
+/// The scalar parameter applied to each normal vector of a normal texture.
+///
+/// Must be finite and non-negative.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct NormalTextureScale(pub f32);
+
+impl Default for NormalTextureScale {
+    fn default() -> Self {
+        NormalTextureScale(1.0)
+    }
+}
+
+impl Validate for NormalTextureScale {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        if !self.0.is_finite() || self.0 < 0.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
 
 /// Defines the occlusion texture of a material.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct OcclusionTexture {}
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OcclusionTexture {
+    /// The index of the texture.
+    pub index: texture::Index<texture::Texture>,
+
+    /// The set index of the texture's `TEXCOORD` attribute.
+    #[serde(rename = "texCoord")]
+    pub tex_coord: u32,
+
+    /// The strength of the occlusion effect, sampled from the texture's R
+    /// channel. A value of 0.0 means no occlusion. A value of 1.0 means
+    /// full occlusion. This value is linear. Must be finite and
+    /// non-negative.
+    pub strength: StrengthFactor,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+impl Default for OcclusionTexture {
+    fn default() -> Self {
+        OcclusionTexture {
+            index: Default::default(),
+            tex_coord: 0,
+            strength: StrengthFactor(1.0),
+            extras: Default::default(),
+        }
+    }
+}
 
 /// The diffuse factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -212,3 +799,56 @@ impl Validate for PbrSpecularFactor {}
 #[cfg(feature = "KHR_materials_unlit")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Unlit {}
+
+#[cfg(all(test, feature = "KHR_materials_pbrSpecularGlossiness"))]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn to_metallic_roughness_pure_dielectric() {
+        let pbr = PbrSpecularGlossiness {
+            diffuse_factor: PbrDiffuseFactor([0.8, 0.2, 0.2, 1.0]),
+            specular_factor: PbrSpecularFactor([0.0, 0.0, 0.0]),
+            glossiness_factor: StrengthFactor(0.5),
+            ..Default::default()
+        };
+        let converted = pbr.to_metallic_roughness();
+        assert_close(converted.metallic_factor.0, 0.0);
+        assert_close(converted.roughness_factor.0, 0.5);
+        assert_close(converted.base_color_factor.0[0], 0.8333);
+        assert_close(converted.base_color_factor.0[1], 0.2083);
+        assert_close(converted.base_color_factor.0[3], 1.0);
+    }
+
+    #[test]
+    fn to_metallic_roughness_pure_metal() {
+        let pbr = PbrSpecularGlossiness {
+            diffuse_factor: PbrDiffuseFactor([0.0, 0.0, 0.0, 1.0]),
+            specular_factor: PbrSpecularFactor([1.0, 1.0, 1.0]),
+            glossiness_factor: StrengthFactor(0.8),
+            ..Default::default()
+        };
+        let converted = pbr.to_metallic_roughness();
+        assert_close(converted.metallic_factor.0, 1.0);
+        assert_close(converted.roughness_factor.0, 0.2);
+        assert_close(converted.base_color_factor.0[0], 1.0);
+    }
+
+    #[test]
+    fn to_metallic_roughness_mid_range() {
+        let pbr = PbrSpecularGlossiness {
+            diffuse_factor: PbrDiffuseFactor([0.3, 0.3, 0.3, 1.0]),
+            specular_factor: PbrSpecularFactor([0.5, 0.5, 0.5]),
+            glossiness_factor: StrengthFactor(0.6),
+            ..Default::default()
+        };
+        let converted = pbr.to_metallic_roughness();
+        assert_close(converted.metallic_factor.0, 0.7583);
+        assert_close(converted.roughness_factor.0, 0.4);
+        assert_close(converted.base_color_factor.0[0], 0.6466);
+    }
+}